@@ -1,7 +1,16 @@
 use std::collections::HashMap;
 
+use crossterm::style::{Color, ResetColor, SetForegroundColor};
+
+#[derive(Clone, Copy)]
+struct Cell {
+    mask: u8,
+    /// Brightest (closest) depth intensity drawn into this cell, 0.0 (far) to 1.0 (near).
+    intensity: f32,
+}
+
 pub struct Canvas {
-    grid: HashMap<(i32, i32), u8>,
+    grid: HashMap<(i32, i32), Cell>,
     min_x: i32,
     max_x: i32,
     min_y: i32,
@@ -59,13 +68,17 @@ impl Canvas {
         ((char_x, char_y), mask)
     }
 
-    pub fn set(&mut self, x: f32, y: f32) {
+    pub fn set(&mut self, x: f32, y: f32, intensity: Option<f32>) {
         let ix = x.round() as i32;
         let iy = y.round() as i32;
 
         let ((cx, cy), mask) = Self::get_pixel_map(ix, iy);
 
-        *self.grid.entry((cx, cy)).or_insert(0) |= mask;
+        let cell = self.grid.entry((cx, cy)).or_insert(Cell { mask: 0, intensity: 0.0 });
+        cell.mask |= mask;
+        if let Some(i) = intensity {
+            cell.intensity = cell.intensity.max(i);
+        }
 
         if cx < self.min_x { self.min_x = cx; }
         if cx > self.max_x { self.max_x = cx; }
@@ -73,7 +86,9 @@ impl Canvas {
         if cy > self.max_y { self.max_y = cy; }
     }
 
-    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) {
+    /// Draw a line, optionally tagging every cell it touches with a depth
+    /// `intensity` (0.0 far, 1.0 near) so `frame` can render it depth-cued.
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, intensity: Option<f32>) {
         let x1 = x1.round() as i32;
         let y1 = y1.round() as i32;
         let x2 = x2.round() as i32;
@@ -89,7 +104,7 @@ impl Canvas {
         let mut y = y1;
 
         loop {
-            self.set(x as f32, y as f32);
+            self.set(x as f32, y as f32, intensity);
             if x == x2 && y == y2 { break; }
             let e2 = 2 * err;
             if e2 >= dy {
@@ -103,7 +118,10 @@ impl Canvas {
         }
     }
 
-    pub fn frame(&self) -> String {
+    /// Render the canvas to Braille glyphs. When `color` is true, each cell
+    /// is preceded by a `SetForegroundColor` escape derived from its depth
+    /// intensity, so closer geometry renders brighter/warmer than far geometry.
+    pub fn frame(&self, color: bool) -> String {
         if self.grid.is_empty() {
             return String::new();
         }
@@ -111,10 +129,16 @@ impl Canvas {
         let mut output = String::new();
         for y in self.min_y..=self.max_y {
             for x in self.min_x..=self.max_x {
-                if let Some(&mask) = self.grid.get(&(x, y)) {
+                if let Some(cell) = self.grid.get(&(x, y)) {
+                    if color {
+                        output.push_str(&SetForegroundColor(depth_color(cell.intensity)).to_string());
+                    }
                     // Braille starts at U+2800
-                    let c = std::char::from_u32(0x2800 + mask as u32).unwrap_or(' ');
+                    let c = std::char::from_u32(0x2800 + cell.mask as u32).unwrap_or(' ');
                     output.push(c);
+                    if color {
+                        output.push_str(&ResetColor.to_string());
+                    }
                 } else {
                     output.push(' '); // Or appropriate empty character, usually space (U+2800 is blank braille pattern but space is better for terminal copy paste)
                     // Actually, U+2800 is empty pattern. Space is space.
@@ -127,3 +151,16 @@ impl Canvas {
         output
     }
 }
+
+/// Map a 0.0 (far) .. 1.0 (near) depth intensity to a warm-near / cool-far RGB.
+fn depth_color(intensity: f32) -> Color {
+    let t = intensity.clamp(0.0, 1.0);
+    let far = (90.0, 100.0, 140.0);
+    let near = (255.0, 200.0, 140.0);
+
+    Color::Rgb {
+        r: (far.0 + (near.0 - far.0) * t) as u8,
+        g: (far.1 + (near.1 - far.1) * t) as u8,
+        b: (far.2 + (near.2 - far.2) * t) as u8,
+    }
+}
@@ -0,0 +1,57 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// If `input` is a local path, return it unchanged. Otherwise, if it looks
+/// like a 4-character PDB accession code, download the structure from
+/// `files.rcsb.org` (caching it on disk) and return the path to the cached
+/// file so the caller can hand it straight to `pdb::read_pdb`.
+pub fn resolve_input(input: &str, format: Option<&str>) -> Result<String> {
+    if Path::new(input).exists() {
+        return Ok(input.to_string());
+    }
+
+    if is_pdb_id(input) {
+        return fetch_pdb_id(input, format);
+    }
+
+    Ok(input.to_string())
+}
+
+fn is_pdb_id(input: &str) -> bool {
+    input.len() == 4 && input.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn cache_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir);
+    base.join("mmterm-rs")
+}
+
+fn fetch_pdb_id(id: &str, format: Option<&str>) -> Result<String> {
+    let id = id.to_ascii_uppercase();
+    let ext = match format.map(str::to_ascii_lowercase).as_deref() {
+        Some("pdb") | Some("ent") => "pdb",
+        _ => "cif",
+    };
+
+    let dir = cache_dir();
+    std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create cache dir {}", dir.display()))?;
+    let cached = dir.join(format!("{id}.{ext}.gz"));
+
+    if !cached.exists() {
+        let url = format!("https://files.rcsb.org/download/{id}.{ext}.gz");
+        let resp = ureq::get(&url)
+            .call()
+            .with_context(|| format!("Failed to fetch {url}"))?;
+
+        let mut body = Vec::new();
+        resp.into_reader().read_to_end(&mut body)?;
+        std::fs::write(&cached, &body).with_context(|| format!("Failed to cache {}", cached.display()))?;
+    }
+
+    Ok(cached.to_string_lossy().into_owned())
+}
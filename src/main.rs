@@ -11,14 +11,17 @@ use crossterm::{
     style::Print,
 };
 use glam::{Vec3, Mat3};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 mod canvas;
+mod fetch;
 mod pdb;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Input PDB file
+    /// Input PDB file, or a 4-character PDB ID to fetch from files.rcsb.org
     input: String,
 
     /// Size of the viewing box
@@ -36,6 +39,10 @@ struct Args {
     /// Format of the input file
     #[arg(short = 'f', long = "format")]
     format: Option<String>,
+
+    /// Disable depth-cued color output, for terminals without truecolor support
+    #[arg(long = "mono")]
+    mono: bool,
 }
 
 // Constants from Python version
@@ -53,7 +60,8 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
-    let models = pdb::read_pdb(&args.input, args.chain.as_deref(), args.format.as_deref()).context("Failed to read PDB file")?;
+    let resolved_input = fetch::resolve_input(&args.input, args.format.as_deref()).context("Failed to resolve input")?;
+    let models = pdb::read_pdb(&resolved_input, args.chain.as_deref(), args.format.as_deref()).context("Failed to read PDB file")?;
 
     // Current state
     let mut curr_model_idx = if args.model > 0 && args.model <= models.len() {
@@ -164,7 +172,7 @@ fn main() -> Result<()> {
             for i in 0..4 {
                 let (x1, y1) = corners[i];
                 let (x2, y2) = corners[(i + 1) % 4];
-                canvas.line(x1, y1, x2, y2);
+                canvas.line(x1, y1, x2, y2, None);
             }
 
             // Transform and Draw Atoms
@@ -192,28 +200,46 @@ fn main() -> Result<()> {
             let translation = Vec3::new(trans_x, trans_y, 0.0);
 
             // Pre-calculate transformed points to avoid recalculating for connections
-            let transformed_points: Vec<Vec3> = model.atoms.iter().map(|atom| {
-                let p = atom.pos + translation; // Translate first (object space)
-                let p = rot_mat_x * p;
-                let p = rot_mat_y * p;
-                p * zoom
-            }).collect();
-
-            // Draw connections
-            for i in 0..model.connections.len() {
-                if model.connections[i] {
-                    let p1 = transformed_points[i];
-                    let p2 = transformed_points[i+1];
-
-                    // Check clipping
-                    // Python: if x_min < x_start < x_max ...
-                    if p1.x > clip_x_min && p1.x < clip_x_max &&
-                       p1.y > clip_y_min && p1.y < clip_y_max &&
-                       p2.x > clip_x_min && p2.x < clip_x_max &&
-                       p2.y > clip_y_min && p2.y < clip_y_max {
-                           canvas.line(p1.x, p1.y, p2.x, p2.y);
-                       }
-                }
+            #[cfg(feature = "rayon")]
+            let transformed_points: Vec<Vec3> = model.atoms.par_iter()
+                .map(|atom| transform_point(atom.pos, translation, rot_mat_x, rot_mat_y, zoom))
+                .collect();
+            #[cfg(not(feature = "rayon"))]
+            let transformed_points: Vec<Vec3> = model.atoms.iter()
+                .map(|atom| transform_point(atom.pos, translation, rot_mat_x, rot_mat_y, zoom))
+                .collect();
+
+            // Draw connections. The clip test for every candidate segment is independent,
+            // so it can run in parallel; only the final `canvas.line` calls (which mutate
+            // the shared grid) have to happen serially afterward.
+            let connected: Vec<usize> = (0..model.connections.len()).filter(|&i| model.connections[i]).collect();
+
+            let clip_window = (clip_x_min, clip_x_max, clip_y_min, clip_y_max);
+            #[cfg(feature = "rayon")]
+            let segments: Vec<(Vec3, Vec3)> = connected.par_iter()
+                .filter_map(|&i| clip_segment(transformed_points[i], transformed_points[i + 1], clip_window))
+                .collect();
+            #[cfg(not(feature = "rayon"))]
+            let segments: Vec<(Vec3, Vec3)> = connected.iter()
+                .filter_map(|&i| clip_segment(transformed_points[i], transformed_points[i + 1], clip_window))
+                .collect();
+
+            // Normalize depth (post-rotation Z) across the model's own transformed
+            // points, so color always spans the full near/far range regardless of zoom.
+            let (z_min, z_max) = transformed_points.iter().fold(
+                (f32::MAX, f32::MIN),
+                |(lo, hi), p| (lo.min(p.z), hi.max(p.z)),
+            );
+            let z_range = (z_max - z_min).max(f32::EPSILON);
+
+            for (p1, p2) in segments {
+                let intensity = if args.mono {
+                    None
+                } else {
+                    let mid_z = (p1.z + p2.z) / 2.0;
+                    Some(((mid_z - z_min) / z_range).clamp(0.0, 1.0))
+                };
+                canvas.line(p1.x, p1.y, p2.x, p2.y, intensity);
             }
 
             // Render
@@ -221,7 +247,7 @@ fn main() -> Result<()> {
             execute!(stdout, Print(format!("{}\r\n", info_str)))?;
             execute!(stdout, Print(format!("{}\r\n", help_str)))?;
             execute!(stdout, MoveTo(0, 2))?; // Canvas starts below info
-            execute!(stdout, Print(canvas.frame()))?;
+            execute!(stdout, Print(canvas.frame(!args.mono)))?;
         }
     }
 
@@ -232,6 +258,25 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+fn transform_point(pos: Vec3, translation: Vec3, rot_mat_x: Mat3, rot_mat_y: Mat3, zoom: f32) -> Vec3 {
+    let p = pos + translation; // Translate first (object space)
+    let p = rot_mat_x * p;
+    let p = rot_mat_y * p;
+    p * zoom
+}
+
+// Python: if x_min < x_start < x_max ...
+fn clip_segment(p1: Vec3, p2: Vec3, (x_min, x_max, y_min, y_max): (f32, f32, f32, f32)) -> Option<(Vec3, Vec3)> {
+    if p1.x > x_min && p1.x < x_max &&
+       p1.y > y_min && p1.y < y_max &&
+       p2.x > x_min && p2.x < x_max &&
+       p2.y > y_min && p2.y < y_max {
+        Some((p1, p2))
+    } else {
+        None
+    }
+}
+
 fn get_bounds(model: &pdb::Model) -> (Vec3, Vec3) {
     let mut min = Vec3::splat(f32::MAX);
     let mut max = Vec3::splat(f32::MIN);
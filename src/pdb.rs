@@ -1,6 +1,7 @@
 use std::fs::File;
 use std::io::{self, BufRead};
 use std::path::Path;
+use flate2::read::GzDecoder;
 use glam::Vec3;
 use anyhow::Result;
 
@@ -33,13 +34,85 @@ impl Model {
     }
 }
 
-pub fn read_pdb<P: AsRef<Path>>(path: P) -> Result<Vec<Model>> {
+const PROTEIN_BB: [&str; 3] = ["N", "CA", "C"];
+const NUCLEIC_BB: [&str; 6] = ["P", "O5'", "C5'", "C4'", "C3'", "O3'"];
+
+pub fn read_pdb<P: AsRef<Path>>(path: P, chain: Option<&str>, format: Option<&str>) -> Result<Vec<Model>> {
+    let path = path.as_ref();
+    let mut reader = open_reader(path)?;
+
+    let is_cif = match format.map(str::to_ascii_lowercase).as_deref() {
+        Some("cif") | Some("mmcif") | Some("pdbx") => true,
+        Some("pdb") | Some("ent") => false,
+        _ => detect_cif(path, &mut reader)?,
+    };
+
+    let mut models = if is_cif {
+        read_cif(reader)?
+    } else {
+        read_pdb_legacy(reader)?
+    };
+
+    if let Some(chain) = chain {
+        let wanted = chain.chars().next();
+        for model in &mut models {
+            let atoms = std::mem::take(&mut model.atoms)
+                .into_iter()
+                .filter(|a| wanted.map(|c| a.chain_id == c).unwrap_or(true))
+                .collect();
+            *model = process_model(atoms);
+        }
+    }
+
+    if models.is_empty() {
+        anyhow::bail!("No atoms found or parsed.");
+    }
+
+    Ok(models)
+}
+
+/// Open `path` for line-oriented reading, transparently unwrapping gzip
+/// (detected by `.gz` extension or the `1f 8b` magic bytes) so callers never
+/// have to care whether the file came straight off `files.rcsb.org`.
+fn open_reader(path: &Path) -> Result<Box<dyn BufRead>> {
     let file = File::open(path)?;
-    let reader = io::BufReader::new(file);
+    let mut reader = io::BufReader::new(file);
+
+    let is_gz = path.extension().map(|e| e.eq_ignore_ascii_case("gz")).unwrap_or(false)
+        || reader.fill_buf()?.starts_with(&[0x1f, 0x8b]);
+
+    if is_gz {
+        Ok(Box::new(io::BufReader::new(GzDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+/// Sniff whether `path` is mmCIF/PDBx: by extension first (ignoring a trailing
+/// `.gz`), falling back to peeking the start of the file for a `data_` token.
+fn detect_cif<R: BufRead>(path: &Path, reader: &mut R) -> Result<bool> {
+    let name = path.to_string_lossy().to_lowercase();
+    let stem = name.strip_suffix(".gz").unwrap_or(&name);
+    if stem.ends_with(".cif") {
+        return Ok(true);
+    }
+    if stem.ends_with(".pdb") || stem.ends_with(".ent") {
+        return Ok(false);
+    }
 
-    let protein_bb = ["N", "CA", "C"];
-    let nucleic_bb = ["P", "O5'", "C5'", "C4'", "C3'", "O3'"];
+    let buf = reader.fill_buf()?;
+    let start = buf
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(buf.len());
+    Ok(buf[start..].starts_with(b"data_"))
+}
 
+fn is_backbone(name: &str) -> bool {
+    PROTEIN_BB.contains(&name) || NUCLEIC_BB.contains(&name)
+}
+
+fn read_pdb_legacy<R: BufRead>(reader: R) -> Result<Vec<Model>> {
     // We only care about models if explicit MODEL record exists, otherwise it's one model.
     // For simplicity, we'll just read all atoms into one list per MODEL block.
     // If no MODEL tags, it's one model.
@@ -63,7 +136,7 @@ pub fn read_pdb<P: AsRef<Path>>(path: P) -> Result<Vec<Model>> {
             if let Some(atom) = parse_atom_line(&line) {
                 // Filter
                 let name = atom.name.trim();
-                if protein_bb.contains(&name) || nucleic_bb.contains(&name) {
+                if is_backbone(name) {
                     current_atoms.push(atom);
                 }
             }
@@ -75,10 +148,6 @@ pub fn read_pdb<P: AsRef<Path>>(path: P) -> Result<Vec<Model>> {
         models.push(process_model(current_atoms));
     }
 
-    if models.is_empty() {
-        anyhow::bail!("No atoms found or parsed.");
-    }
-
     Ok(models)
 }
 
@@ -122,6 +191,173 @@ fn parse_atom_line(line: &str) -> Option<Atom> {
     })
 }
 
+/// Column indices of the `_atom_site.*` tags we care about, resolved once per
+/// `loop_` block from the tag list that precedes the data rows.
+struct AtomSiteCols {
+    group_pdb: usize,
+    atom_id: usize,
+    comp_id: usize,
+    asym_id: usize,
+    seq_id: usize,
+    x: usize,
+    y: usize,
+    z: usize,
+    model_num: usize,
+    ncols: usize,
+}
+
+impl AtomSiteCols {
+    fn resolve(tags: &[String]) -> Option<Self> {
+        let find = |name: &str| tags.iter().position(|t| t == name);
+        Some(AtomSiteCols {
+            group_pdb: find("_atom_site.group_PDB")?,
+            atom_id: find("_atom_site.label_atom_id")?,
+            comp_id: find("_atom_site.label_comp_id")?,
+            asym_id: find("_atom_site.auth_asym_id").or_else(|| find("_atom_site.label_asym_id"))?,
+            seq_id: find("_atom_site.auth_seq_id")?,
+            x: find("_atom_site.Cartn_x")?,
+            y: find("_atom_site.Cartn_y")?,
+            z: find("_atom_site.Cartn_z")?,
+            model_num: find("_atom_site.pdbx_PDB_model_num")?,
+            ncols: tags.len(),
+        })
+    }
+}
+
+fn read_cif<R: BufRead>(reader: R) -> Result<Vec<Model>> {
+    let mut tags: Vec<String> = Vec::new();
+    let mut collecting_tags = false;
+    let mut cols: Option<AtomSiteCols> = None;
+
+    // Atoms grouped by their `pdbx_PDB_model_num`, preserving first-seen order.
+    let mut model_order: Vec<i64> = Vec::new();
+    let mut model_atoms: std::collections::HashMap<i64, Vec<Atom>> = std::collections::HashMap::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if trimmed == "loop_" {
+            tags.clear();
+            collecting_tags = true;
+            cols = None;
+            continue;
+        }
+
+        if collecting_tags {
+            if trimmed.starts_with('_') {
+                tags.push(trimmed.to_string());
+                continue;
+            }
+            collecting_tags = false;
+            cols = AtomSiteCols::resolve(&tags);
+        }
+
+        let Some(c) = &cols else { continue };
+
+        if trimmed.starts_with('_') || trimmed.starts_with("data_") {
+            cols = None;
+            continue;
+        }
+
+        let fields = split_cif_row(trimmed);
+        if fields.len() < c.ncols {
+            continue;
+        }
+
+        let name = fields[c.atom_id].trim_matches('"');
+        if !is_backbone(name) {
+            continue;
+        }
+
+        // `group_PDB` ("ATOM"/"HETATM") only gates which rows we accept; mmCIF has no
+        // direct analogue of the legacy serial column, so we leave it unset.
+        if fields[c.group_pdb].trim_matches('"') != "ATOM" && fields[c.group_pdb].trim_matches('"') != "HETATM" {
+            continue;
+        }
+
+        let (Ok(res_seq), Ok(model_num)) = (
+            fields[c.seq_id].parse::<i32>(),
+            fields[c.model_num].parse::<i64>(),
+        ) else {
+            continue;
+        };
+
+        let (Ok(x), Ok(y), Ok(z)) = (
+            fields[c.x].parse::<f32>(),
+            fields[c.y].parse::<f32>(),
+            fields[c.z].parse::<f32>(),
+        ) else {
+            continue;
+        };
+
+        let atom = Atom {
+            serial: 0,
+            name: name.to_string(),
+            res_name: fields[c.comp_id].trim_matches('"').to_string(),
+            chain_id: fields[c.asym_id].chars().next().unwrap_or(' '),
+            res_seq,
+            pos: Vec3::new(x, y, z),
+        };
+
+        model_atoms.entry(model_num).or_insert_with(|| {
+            model_order.push(model_num);
+            Vec::new()
+        }).push(atom);
+    }
+
+    let mut models = Vec::with_capacity(model_order.len());
+    for num in model_order {
+        if let Some(atoms) = model_atoms.remove(&num) {
+            models.push(process_model(atoms));
+        }
+    }
+
+    Ok(models)
+}
+
+/// Whitespace-split a CIF data row, treating `'...'`/`"..."` runs as single
+/// fields so values containing spaces (e.g. quoted atom names) survive.
+fn split_cif_row(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        if c == '\'' || c == '"' {
+            let quote = c;
+            chars.next();
+            let mut field = String::new();
+            for c2 in chars.by_ref() {
+                if c2 == quote {
+                    break;
+                }
+                field.push(c2);
+            }
+            fields.push(field);
+        } else {
+            let mut field = String::new();
+            while let Some(&c2) = chars.peek() {
+                if c2.is_whitespace() {
+                    break;
+                }
+                field.push(c2);
+                chars.next();
+            }
+            fields.push(field);
+        }
+    }
+
+    fields
+}
+
 fn process_model(mut atoms: Vec<Atom>) -> Model {
     if atoms.is_empty() {
         return Model::new();